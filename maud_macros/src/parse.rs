@@ -1,7 +1,8 @@
 use std::mem;
-use syntax::ast::{Expr, ExprParen, Lit, Stmt, TokenTree, TtDelimited, TtToken};
+use syntax::ast::{Expr, ExprParen, Lit, Pat, Stmt, TokenTree, TtDelimited, TtToken};
 use syntax::codemap::Span;
 use syntax::ext::base::ExtCtxt;
+use syntax::ext::build::AstBuilder;
 use syntax::parse;
 use syntax::parse::parser::Parser as RustParser;
 use syntax::parse::token::{self, DelimToken};
@@ -27,6 +28,12 @@ macro_rules! question {
 macro_rules! semi {
     () => (TtToken(_, token::Semi))
 }
+macro_rules! comma {
+    () => (TtToken(_, token::Comma))
+}
+macro_rules! fat_arrow {
+    () => (TtToken(_, token::FatArrow))
+}
 macro_rules! minus {
     () => (TtToken(_, token::BinOp(token::Minus)))
 }
@@ -102,6 +109,41 @@ impl<'cx, 's, 'i> Parser<'cx, 's, 'i> {
                 self.shift(2);
                 self.if_expr(sp);
             },
+            // For
+            [dollar!(), ident!(sp, name), ..] if name.as_str() == "for" => {
+                self.shift(2);
+                self.for_expr(sp);
+            },
+            // While
+            [dollar!(), ident!(sp, name), ..] if name.as_str() == "while" => {
+                self.shift(2);
+                self.while_expr(sp);
+            },
+            // Loop
+            [dollar!(), ident!(sp, name), ..] if name.as_str() == "loop" => {
+                self.shift(2);
+                self.loop_expr(sp);
+            },
+            // Break
+            [dollar!(), ident!(sp, name), ..] if name.as_str() == "break" => {
+                self.shift(2);
+                self.render.emit_break(sp);
+            },
+            // Continue
+            [dollar!(), ident!(sp, name), ..] if name.as_str() == "continue" => {
+                self.shift(2);
+                self.render.emit_continue(sp);
+            },
+            // Match
+            [dollar!(), ident!(sp, name), ..] if name.as_str() == "match" => {
+                self.shift(2);
+                self.match_expr(sp);
+            },
+            // Let
+            [dollar!(), ident!(sp, name), ..] if name.as_str() == "let" => {
+                self.shift(2);
+                self.let_expr(sp);
+            },
             // Splice
             [ref tt @ dollar!(), dollar!(), ..] => {
                 self.shift(2);
@@ -159,7 +201,10 @@ impl<'cx, 's, 'i> Parser<'cx, 's, 'i> {
                 self.shift(1);
                 cond_tts.push(tt.clone());
             },
-            [] => self.render.cx.span_fatal(sp, "expected body for this `if`"),
+            [] => {
+                self.render.cx.span_err(sp, "expected body for this `if`");
+                return;
+            },
         }}
         let if_cond = self.new_rust_parser(cond_tts).parse_expr();
         // Parse the (optional) else
@@ -184,7 +229,10 @@ impl<'cx, 's, 'i> Parser<'cx, 's, 'i> {
                         self.shift(1);
                         Some(self.block(sp, &d.tts))
                     },
-                    _ => self.render.cx.span_fatal(sp, "invalid syntax"),
+                    _ => {
+                        self.render.cx.span_err(sp, "invalid syntax");
+                        None
+                    },
                 }
             },
             _ => None,
@@ -192,6 +240,217 @@ impl<'cx, 's, 'i> Parser<'cx, 's, 'i> {
         self.render.emit_if(if_cond, if_body, else_body);
     }
 
+    fn for_expr(&mut self, sp: Span) {
+        // Munch the head (pattern + `in` + iterable expression)
+        let mut head_tts = vec![];
+        let body;
+        loop { match self.input {
+            [TtDelimited(sp, ref d), ..] if d.delim == DelimToken::Brace => {
+                self.shift(1);
+                body = self.block(sp, &d.tts);
+                break;
+            },
+            [ref tt, ..] => {
+                self.shift(1);
+                head_tts.push(tt.clone());
+            },
+            [] => {
+                self.render.cx.span_err(sp, "expected body for this `$for`");
+                return;
+            },
+        }}
+        let mut head_parser = self.new_rust_parser(head_tts);
+        let pat = head_parser.parse_pat();
+        match head_parser.token {
+            token::Ident(ref ident, _) if ident.name.as_str() == "in" => {
+                head_parser.bump();
+                let iter_expr = head_parser.parse_expr();
+                self.render.emit_for(pat, iter_expr, body);
+            },
+            _ => {
+                // Drop the loop and its body, so that names the missing
+                // pattern would have bound are not reported again
+                self.render.cx.span_err(sp, "expected `in` in this `$for`");
+            },
+        }
+    }
+
+    fn while_expr(&mut self, sp: Span) {
+        // Munch the condition, stopping at the brace-delimited body
+        let mut cond_tts = vec![];
+        let body;
+        loop { match self.input {
+            [TtDelimited(sp, ref d), ..] if d.delim == DelimToken::Brace => {
+                self.shift(1);
+                body = self.block(sp, &d.tts);
+                break;
+            },
+            [ref tt, ..] => {
+                self.shift(1);
+                cond_tts.push(tt.clone());
+            },
+            [] => {
+                self.render.cx.span_err(sp, "expected body for this `$while`");
+                return;
+            },
+        }}
+        let cond = self.new_rust_parser(cond_tts).parse_expr();
+        self.render.emit_while(cond, body);
+    }
+
+    fn loop_expr(&mut self, sp: Span) {
+        match self.input {
+            [TtDelimited(sp, ref d), ..] if d.delim == DelimToken::Brace => {
+                self.shift(1);
+                let body = self.block(sp, &d.tts);
+                self.render.emit_loop(body);
+            },
+            _ => self.render.cx.span_err(sp, "expected body for this `$loop`"),
+        }
+    }
+
+    fn match_expr(&mut self, sp: Span) {
+        // Munch the scrutinee up to the brace-delimited arm list
+        let mut scrutinee_tts = vec![];
+        let arms;
+        loop { match self.input {
+            [TtDelimited(sp, ref d), ..] if d.delim == DelimToken::Brace => {
+                self.shift(1);
+                arms = match self.match_arms(&d.tts) {
+                    Some(arms) => arms,
+                    // A malformed arm has already been reported
+                    None => return,
+                };
+                break;
+            },
+            [ref tt, ..] => {
+                self.shift(1);
+                scrutinee_tts.push(tt.clone());
+            },
+            [] => {
+                self.render.cx.span_err(sp, "expected body for this `$match`");
+                return;
+            },
+        }}
+        let scrutinee = self.new_rust_parser(scrutinee_tts).parse_expr();
+        if arms.is_empty() {
+            self.render.cx.span_err(sp, "`$match` must have at least one arm");
+            return;
+        }
+        self.render.emit_match(scrutinee, arms);
+    }
+
+    /// Parse the arms of a `$match`, returning each as a pattern, an
+    /// optional `$if` guard, and the statements for its body. Returns `None`
+    /// once an arm is malformed; the error has then already been reported.
+    fn match_arms(&mut self, tts: &[TokenTree]) -> Option<Vec<(P<Pat>, Option<P<Expr>>, Vec<P<Stmt>>)>> {
+        let mut arms = vec![];
+        let mut input = tts;
+        loop { match input {
+            [] => break,
+            [comma!(), ..] => input = &input[1..],
+            _ => {
+                // Collect the pattern, stopping at `=>` or a `$if` guard
+                let arm_sp = input[0].get_span();
+                let mut pat_tts = vec![];
+                let mut guard = None;
+                loop { match input {
+                    [fat_arrow!(), ..] => {
+                        input = &input[1..];
+                        break;
+                    },
+                    [dollar!(), ident!(_, name), ..] if name.as_str() == "if" => {
+                        input = &input[2..];
+                        let mut guard_tts = vec![];
+                        loop { match input {
+                            [fat_arrow!(), ..] => {
+                                input = &input[1..];
+                                break;
+                            },
+                            [ref tt, ..] => {
+                                input = &input[1..];
+                                guard_tts.push(tt.clone());
+                            },
+                            [] => {
+                                self.render.cx.span_err(self.span, "expected `=>` after this `$if` guard");
+                                return None;
+                            },
+                        }}
+                        guard = Some(self.new_rust_parser(guard_tts).parse_expr());
+                        break;
+                    },
+                    [ref tt, ..] => {
+                        input = &input[1..];
+                        pat_tts.push(tt.clone());
+                    },
+                    [] => {
+                        self.render.cx.span_err(self.span, "expected `=>` for this match arm");
+                        return None;
+                    },
+                }}
+                if pat_tts.is_empty() {
+                    self.render.cx.span_err(arm_sp, "expected a pattern for this match arm");
+                    return None;
+                }
+                let pat = self.new_rust_parser(pat_tts).parse_pat();
+                // Parse the arm body, either a brace block or a single markup
+                let body = match input {
+                    [TtDelimited(sp, ref d), ..] if d.delim == DelimToken::Brace => {
+                        input = &input[1..];
+                        self.block(sp, &d.tts)
+                    },
+                    _ => {
+                        let body_tts = input;
+                        let mut len = 0;
+                        loop { match input {
+                            [] | [comma!(), ..] => break,
+                            [_, ..] => {
+                                input = &input[1..];
+                                len += 1;
+                            },
+                        }}
+                        let mut render = self.render.fork();
+                        mem::swap(&mut self.render, &mut render);
+                        let saved_input = mem::replace(&mut self.input, &body_tts[..len]);
+                        self.markups();
+                        self.input = saved_input;
+                        mem::swap(&mut self.render, &mut render);
+                        render.into_stmts()
+                    },
+                };
+                arms.push((pat, guard, body));
+            },
+        }}
+        Some(arms)
+    }
+
+    fn let_expr(&mut self, sp: Span) {
+        // Munch the binding, including its terminating semicolon. The `$`
+        // and `let` ident were already consumed by the dispatcher in
+        // `markup()`, so re-synthesize the `let` keyword here -- otherwise
+        // the Rust parser sees a bare assignment instead of a `Local`.
+        let mut let_tts = vec![
+            TtToken(sp, token::Ident(token::str_to_ident("let"), token::IdentStyle::Plain)),
+        ];
+        loop { match self.input {
+            [ref tt @ semi!(), ..] => {
+                self.shift(1);
+                let_tts.push(tt.clone());
+                break;
+            },
+            [ref tt, ..] => {
+                self.shift(1);
+                let_tts.push(tt.clone());
+            },
+            [] => {
+                self.render.cx.span_err(sp, "expected `;` to terminate this `$let`");
+                return;
+            },
+        }}
+        let stmt = self.new_rust_parser(let_tts).parse_stmt();
+        self.render.push_let(stmt);
+    }
+
     fn splice(&mut self, sp: Span) -> P<Expr> {
         let mut tts = vec![];
         // First, munch a single token tree
@@ -216,7 +475,8 @@ impl<'cx, 's, 'i> Parser<'cx, 's, 'i> {
             }
         }
         if tts.is_empty() {
-            self.render.cx.span_fatal(sp, "expected expression for this splice");
+            self.render.cx.span_err(sp, "expected expression for this splice");
+            self.render.cx.expr_tuple(sp, vec![])
         } else {
             self.new_rust_parser(tts).parse_expr()
         }
@@ -0,0 +1,24 @@
+// Exercises the `$for` recovery paths added in chunk0-1/chunk0-4: a
+// malformed `$for` should report a `span_err` and let the rest of the
+// template keep parsing, instead of aborting the whole `cargo build` on
+// the first mistake. Run under this crate's compile-fail harness
+// (e.g. `compiletest-rs`), which checks the `//~ ERROR` annotations
+// against the compiler's diagnostics.
+
+#![feature(plugin)]
+#![plugin(maud_macros)]
+
+extern crate maud;
+
+fn main() {
+    let items = vec![1, 2, 3];
+    let _ = html! {
+        // Missing the `in` keyword.
+        $for item items { //~ ERROR expected `in` in this `$for`
+            li { $item }
+        }
+        // A second, unrelated mistake further down. If recovery works,
+        // this is reported too instead of being silently skipped.
+        span { $nonexistent } //~ ERROR cannot find value `nonexistent` in this scope
+    };
+}
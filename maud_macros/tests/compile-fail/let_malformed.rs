@@ -0,0 +1,15 @@
+// Exercises the `$let` recovery path added in chunk0-3/chunk0-4: a
+// binding with no terminating `;` should report a `span_err` and let the
+// rest of the block keep parsing.
+
+#![feature(plugin)]
+#![plugin(maud_macros)]
+
+extern crate maud;
+
+fn main() {
+    let _ = html! {
+        $let name = "Kara" //~ ERROR expected `;` to terminate this `$let`
+        h1 { $name } //~ ERROR cannot find value `name` in this scope
+    };
+}
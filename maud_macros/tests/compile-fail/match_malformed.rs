@@ -0,0 +1,17 @@
+// Exercises the `$match` recovery path added in chunk0-2/chunk0-4: an arm
+// with no pattern before `=>` should report a `span_err` rather than
+// driving the Rust sub-parser into its own fatal "unexpected token" abort.
+
+#![feature(plugin)]
+#![plugin(maud_macros)]
+
+extern crate maud;
+
+fn main() {
+    let value = 5;
+    let _ = html! {
+        $match value {
+            => { "empty pattern" } //~ ERROR expected a pattern for this match arm
+        }
+    };
+}
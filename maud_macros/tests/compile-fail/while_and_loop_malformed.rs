@@ -0,0 +1,16 @@
+// Exercises the `$while`/`$loop` recovery paths added in chunk0-5/chunk0-4:
+// a missing body should report a `span_err` instead of aborting.
+
+#![feature(plugin)]
+#![plugin(maud_macros)]
+
+extern crate maud;
+
+fn main() {
+    let _ = html! {
+        $while true //~ ERROR expected body for this `$while`
+    };
+    let _ = html! {
+        $loop //~ ERROR expected body for this `$loop`
+    };
+}
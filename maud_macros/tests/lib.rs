@@ -0,0 +1,79 @@
+#![feature(plugin)]
+#![plugin(maud_macros)]
+
+extern crate maud;
+
+use maud::Markup;
+
+#[test]
+fn test_for() {
+    let items = vec!["a", "b", "c"];
+    let rendered = html! {
+        ul {
+            $for item in &items {
+                li { $item }
+            }
+        }
+    };
+    assert_eq!(rendered.into_string(), "<ul><li>a</li><li>b</li><li>c</li></ul>");
+}
+
+#[test]
+fn test_match() {
+    let value = Some(5);
+    let rendered = html! {
+        $match value {
+            Some(x) $if x > 0 => { "positive" }
+            Some(_) => { "non-positive" }
+            None => { "nothing" }
+        }
+    };
+    assert_eq!(rendered.into_string(), "positive");
+}
+
+#[test]
+fn test_let() {
+    struct User { first: &'static str, last: &'static str }
+    impl User {
+        fn full_name(&self) -> String {
+            format!("{} {}", self.first, self.last)
+        }
+    }
+    let user = User { first: "Kara", last: "Danvers" };
+    let rendered = html! {
+        $let name = user.full_name();
+        h1 { $name }
+        title { $name }
+    };
+    assert_eq!(rendered.into_string(), "<h1>Kara Danvers</h1><title>Kara Danvers</title>");
+}
+
+#[test]
+fn test_while() {
+    let mut it = vec!["a", "b", "c"].into_iter().peekable();
+    let rendered = html! {
+        ul {
+            $while it.peek().is_some() {
+                li { $(it.next().unwrap()) }
+            }
+        }
+    };
+    assert_eq!(rendered.into_string(), "<ul><li>a</li><li>b</li><li>c</li></ul>");
+}
+
+#[test]
+fn test_loop_with_break_and_continue() {
+    let mut it = (1..6).into_iter();
+    let rendered = html! {
+        ul {
+            $loop {
+                $match it.next() {
+                    None => { $break }
+                    Some(x) $if x % 2 == 0 => { $continue }
+                    Some(x) => { li { $x } }
+                }
+            }
+        }
+    };
+    assert_eq!(rendered.into_string(), "<ul><li>1</li><li>3</li><li>5</li></ul>");
+}